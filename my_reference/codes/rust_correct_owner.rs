@@ -1,53 +1,399 @@
-#[derive(Debug, Clone)]
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug)]
+enum TaskError {
+    NotFound(u32),
+    Io(String),
+    Serde(String),
+    ProjectNotFound(String),
+    DuplicateProject(String),
+}
+
+impl fmt::Display for TaskError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskError::NotFound(id) => write!(f, "no task with id {id}"),
+            TaskError::Io(msg) => write!(f, "io error: {msg}"),
+            TaskError::Serde(msg) => write!(f, "serialization error: {msg}"),
+            TaskError::ProjectNotFound(name) => write!(f, "no project named {name}"),
+            TaskError::DuplicateProject(name) => write!(f, "a project named {name} already exists"),
+        }
+    }
+}
+
+impl From<io::Error> for TaskError {
+    fn from(err: io::Error) -> Self {
+        TaskError::Io(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for TaskError {
+    fn from(err: serde_json::Error) -> Self {
+        TaskError::Serde(err.to_string())
+    }
+}
+
+impl std::error::Error for TaskError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+enum Priority {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 struct Task {
     id: u32,
     title: String,
     done: bool,
+    priority: Priority,
+}
+
+impl Task {
+    fn sort_key(&self) -> (bool, std::cmp::Reverse<Priority>, u32) {
+        (self.done, std::cmp::Reverse(self.priority), self.id)
+    }
+}
+
+impl PartialOrd for Task {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Task {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
 }
 
-struct TaskManager {
+#[derive(Debug, Serialize, Deserialize)]
+struct Project {
+    name: String,
     tasks: Vec<Task>,
     next_id: u32,
 }
 
-impl TaskManager {
-    fn new() -> Self {
-        TaskManager { tasks: vec![], next_id: 1 }
+impl Project {
+    fn new(name: String) -> Self {
+        Project { name, tasks: vec![], next_id: 1 }
     }
-    
-    fn create(&mut self, title: String) -> u32 {
+
+    fn create(&mut self, title: String, priority: Priority) -> u32 {
         let id = self.next_id;
         self.next_id += 1;
-        self.tasks.push(Task { id, title, done: false });
+        self.push(Task { id, title, done: false, priority });
         id
     }
-    
+
+    fn push(&mut self, task: Task) {
+        self.tasks.push(task);
+        self.tasks.sort();
+    }
+
     fn get(&self, id: u32) -> Option<&Task> {
         self.tasks.iter().find(|t| t.id == id)
     }
-    
+
     fn list(&self) -> &[Task] {
         &self.tasks
     }
-    
-    fn mark_done(&mut self, id: u32) -> bool {
+
+    fn progress(&self) -> (usize, usize, f32) {
+        let total = self.tasks.len();
+        let done = self.tasks.iter().filter(|t| t.done).count();
+        let ratio = if total == 0 { 0.0 } else { done as f32 / total as f32 };
+        (done, total, ratio)
+    }
+
+    fn summary(&self) -> String {
+        const WIDTH: usize = 10;
+        let (done, total, ratio) = self.progress();
+        let filled = (ratio * WIDTH as f32).round() as usize;
+        let bar: String = "#".repeat(filled) + &"-".repeat(WIDTH - filled);
+        format!("{done}/{total} ({:.0}%) [{bar}]", ratio * 100.0)
+    }
+
+    fn mark_done(&mut self, id: u32) -> Result<(), TaskError> {
         match self.tasks.iter_mut().find(|t| t.id == id) {
-            Some(task) => { task.done = true; true }
-            None => false
+            Some(task) => { task.done = true; self.tasks.sort(); Ok(()) }
+            None => Err(TaskError::NotFound(id)),
         }
     }
-    
-    fn delete(&mut self, id: u32) -> Option<Task> {
+
+    fn delete(&mut self, id: u32) -> Result<Task, TaskError> {
         self.tasks
             .iter()
             .position(|t| t.id == id)
             .map(|pos| self.tasks.remove(pos))
+            .ok_or(TaskError::NotFound(id))
+    }
+
+    fn edit<F: FnOnce(&mut Task)>(&mut self, id: u32, transform: F) -> Result<(), TaskError> {
+        match self.tasks.iter_mut().find(|t| t.id == id) {
+            Some(task) => { transform(task); self.tasks.sort(); Ok(()) }
+            None => Err(TaskError::NotFound(id)),
+        }
+    }
+
+    fn save_to_path(&self, path: &Path) -> Result<(), TaskError> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    fn load_from_path(path: &Path) -> Result<Project, TaskError> {
+        if !path.exists() {
+            return Ok(Project::new("default".to_string()));
+        }
+        let contents = fs::read_to_string(path)?;
+        let project = serde_json::from_str(&contents)?;
+        Ok(project)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Workspace {
+    projects: Vec<Project>,
+}
+
+impl Workspace {
+    fn new() -> Self {
+        Workspace { projects: vec![] }
+    }
+
+    fn create_project(&mut self, name: String) -> Result<(), TaskError> {
+        if self.projects.iter().any(|p| p.name == name) {
+            return Err(TaskError::DuplicateProject(name));
+        }
+        self.projects.push(Project::new(name));
+        Ok(())
+    }
+
+    fn rename_project(&mut self, name: &str, new_name: String) -> Result<(), TaskError> {
+        if self.projects.iter().any(|p| p.name == new_name) {
+            return Err(TaskError::DuplicateProject(new_name));
+        }
+        let project = self.project_mut(name)?;
+        project.name = new_name;
+        Ok(())
+    }
+
+    fn delete_project(&mut self, name: &str) -> Result<Project, TaskError> {
+        self.projects
+            .iter()
+            .position(|p| p.name == name)
+            .map(|pos| self.projects.remove(pos))
+            .ok_or_else(|| TaskError::ProjectNotFound(name.to_string()))
+    }
+
+    fn move_task(&mut self, task_id: u32, from: &str, to: &str) -> Result<(), TaskError> {
+        if !self.projects.iter().any(|p| p.name == to) {
+            return Err(TaskError::ProjectNotFound(to.to_string()));
+        }
+        let mut task = self.project_mut(from)?.delete(task_id)?;
+        let dest = self.project_mut(to)?;
+        task.id = dest.next_id;
+        dest.next_id += 1;
+        dest.push(task);
+        Ok(())
+    }
+
+    fn project(&self, name: &str) -> Result<&Project, TaskError> {
+        self.projects
+            .iter()
+            .find(|p| p.name == name)
+            .ok_or_else(|| TaskError::ProjectNotFound(name.to_string()))
+    }
+
+    fn project_mut(&mut self, name: &str) -> Result<&mut Project, TaskError> {
+        self.projects
+            .iter_mut()
+            .find(|p| p.name == name)
+            .ok_or_else(|| TaskError::ProjectNotFound(name.to_string()))
     }
 }
 
 fn main() {
-    let mut manager = TaskManager::new();
-    
-    
-    
-}
\ No newline at end of file
+    let mut workspace = Workspace::new();
+
+
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("rust_correct_owner_{}_{}.json", std::process::id(), name))
+    }
+
+    #[test]
+    fn save_and_load_round_trips() {
+        let path = temp_path("round_trip");
+        let mut project = Project::new("home".to_string());
+        project.create("Buy milk".to_string(), Priority::Medium);
+        project.create("Launch rocket".to_string(), Priority::High);
+
+        project.save_to_path(&path).expect("save should succeed");
+        let loaded = Project::load_from_path(&path).expect("load should succeed");
+
+        assert_eq!(loaded.name, project.name);
+        assert_eq!(loaded.next_id, project.next_id);
+        assert_eq!(loaded.tasks, project.tasks);
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn load_missing_file_returns_empty_project() {
+        let path = temp_path("missing");
+        let _ = fs::remove_file(&path);
+
+        let loaded = Project::load_from_path(&path).expect("missing file is not an error");
+        assert!(loaded.tasks.is_empty());
+    }
+
+    #[test]
+    fn load_malformed_file_returns_serde_error() {
+        let path = temp_path("malformed");
+        fs::write(&path, "not valid json").unwrap();
+
+        let result = Project::load_from_path(&path);
+        assert!(matches!(result, Err(TaskError::Serde(_))));
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn move_task_renumbers_on_id_collision() {
+        let mut workspace = Workspace::new();
+        workspace.create_project("a".to_string()).unwrap();
+        workspace.create_project("b".to_string()).unwrap();
+
+        let id_in_a = workspace.project_mut("a").unwrap().create("from a".to_string(), Priority::Low);
+        let id_in_b = workspace.project_mut("b").unwrap().create("from b".to_string(), Priority::Low);
+        assert_eq!(id_in_a, id_in_b);
+
+        workspace.move_task(id_in_a, "a", "b").unwrap();
+
+        let dest = workspace.project("b").unwrap();
+        let ids: Vec<u32> = dest.list().iter().map(|t| t.id).collect();
+        assert_eq!(ids.len(), 2);
+        assert_ne!(ids[0], ids[1]);
+        assert!(dest.get(id_in_b).is_some());
+    }
+
+    #[test]
+    fn move_task_to_missing_project_keeps_task_in_source() {
+        let mut workspace = Workspace::new();
+        workspace.create_project("a".to_string()).unwrap();
+
+        let id = workspace.project_mut("a").unwrap().create("from a".to_string(), Priority::Low);
+
+        let result = workspace.move_task(id, "a", "nonexistent");
+        assert!(matches!(result, Err(TaskError::ProjectNotFound(_))));
+        assert!(workspace.project("a").unwrap().get(id).is_some());
+    }
+
+    #[test]
+    fn create_project_rejects_duplicate_name() {
+        let mut workspace = Workspace::new();
+        workspace.create_project("a".to_string()).unwrap();
+
+        let result = workspace.create_project("a".to_string());
+        assert!(matches!(result, Err(TaskError::DuplicateProject(_))));
+        assert_eq!(workspace.projects.len(), 1);
+    }
+
+    #[test]
+    fn delete_project_removes_it_and_reports_not_found() {
+        let mut workspace = Workspace::new();
+        workspace.create_project("a".to_string()).unwrap();
+
+        let removed = workspace.delete_project("a").unwrap();
+        assert_eq!(removed.name, "a");
+        assert!(workspace.project("a").is_err());
+
+        let result = workspace.delete_project("a");
+        assert!(matches!(result, Err(TaskError::ProjectNotFound(_))));
+    }
+
+    #[test]
+    fn rename_project_rejects_collision_with_existing_name() {
+        let mut workspace = Workspace::new();
+        workspace.create_project("a".to_string()).unwrap();
+        workspace.create_project("b".to_string()).unwrap();
+        workspace.project_mut("b").unwrap().create("keep me".to_string(), Priority::Low);
+
+        let result = workspace.rename_project("b", "a".to_string());
+        assert!(matches!(result, Err(TaskError::DuplicateProject(_))));
+        assert!(!workspace.project("b").unwrap().list().is_empty());
+    }
+
+    #[test]
+    fn mark_done_sets_flag_and_reports_not_found() {
+        let mut project = Project::new("home".to_string());
+        let id = project.create("Buy milk".to_string(), Priority::Low);
+
+        project.mark_done(id).unwrap();
+        assert!(project.get(id).unwrap().done);
+
+        let result = project.mark_done(id + 1);
+        assert!(matches!(result, Err(TaskError::NotFound(_))));
+    }
+
+    #[test]
+    fn delete_removes_task_and_reports_not_found() {
+        let mut project = Project::new("home".to_string());
+        let id = project.create("Buy milk".to_string(), Priority::Low);
+
+        let removed = project.delete(id).unwrap();
+        assert_eq!(removed.id, id);
+        assert!(project.get(id).is_none());
+
+        let result = project.delete(id);
+        assert!(matches!(result, Err(TaskError::NotFound(_))));
+    }
+
+    #[test]
+    fn edit_applies_transform_and_reports_not_found() {
+        let mut project = Project::new("home".to_string());
+        let id = project.create("Buy milk".to_string(), Priority::Low);
+
+        project.edit(id, |task| task.title = "Buy oat milk".to_string()).unwrap();
+        assert_eq!(project.get(id).unwrap().title, "Buy oat milk");
+
+        let result = project.edit(id + 1, |task| task.done = true);
+        assert!(matches!(result, Err(TaskError::NotFound(_))));
+    }
+
+    #[test]
+    fn progress_counts_done_tasks() {
+        let mut project = Project::new("home".to_string());
+        let id = project.create("Buy milk".to_string(), Priority::Low);
+        project.create("Launch rocket".to_string(), Priority::High);
+        project.mark_done(id).unwrap();
+
+        let (done, total, ratio) = project.progress();
+        assert_eq!(done, 1);
+        assert_eq!(total, 2);
+        assert_eq!(ratio, 0.5);
+    }
+
+    #[test]
+    fn summary_renders_progress_bar() {
+        let mut project = Project::new("home".to_string());
+        let id = project.create("Buy milk".to_string(), Priority::Low);
+        project.mark_done(id).unwrap();
+
+        assert_eq!(project.summary(), "1/1 (100%) [##########]");
+    }
+}